@@ -4,6 +4,8 @@
 
 use crate::prelude::*;
 use bevy::prelude::*;
+use bevy::reflect::{impl_from_reflect_value, impl_reflect_value};
+use bitflags::bitflags;
 
 /// Renders physics objects and events like [AABBs](ColliderAabb) and [contacts](Collision) for debugging purposes.
 ///
@@ -13,54 +15,185 @@ pub struct PhysicsDebugPlugin;
 impl Plugin for PhysicsDebugPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<PhysicsDebugConfig>()
+            .init_resource::<DebugRenderStyle>()
             .insert_resource(GizmoConfig {
-                line_width: 1.0,
+                line_width: DebugRenderStyle::default().line_width,
                 ..default()
             })
             .register_type::<PhysicsDebugConfig>()
+            .register_type::<DebugRenderMode>()
+            .register_type::<DebugRenderStyle>()
+            .register_type::<ColliderDebugColor>()
+            // keep the gizmo line width in sync with the style resource so it can be restyled at runtime.
+            .add_systems(PostUpdate, sync_gizmo_config.before(PhysicsSet::Sync))
             // render AABBs first, so collider shapes drawn over the top. Looks better.
             .add_systems(
                 PostUpdate,
                 (
                     debug_render_aabbs
-                        .run_if(|config: Res<PhysicsDebugConfig>| config.render_aabbs)
+                        .run_if(|config: Res<PhysicsDebugConfig>| {
+                            config.mode.contains(DebugRenderMode::COLLIDER_AABBS)
+                        })
                         .after(PhysicsSet::Sync),
                     debug_render_colliders
-                        .run_if(|config: Res<PhysicsDebugConfig>| config.render_colliders)
+                        .run_if(|config: Res<PhysicsDebugConfig>| {
+                            config.mode.contains(DebugRenderMode::COLLIDER_SHAPES)
+                        })
                         .after(PhysicsSet::Sync),
                     debug_render_contacts
-                        .run_if(|config: Res<PhysicsDebugConfig>| config.render_contacts)
+                        .run_if(|config: Res<PhysicsDebugConfig>| {
+                            config
+                                .mode
+                                .intersects(DebugRenderMode::CONTACT_POINTS | DebugRenderMode::CONTACT_NORMALS)
+                        })
+                        .after(PhysicsSet::Sync),
+                    debug_render_axes
+                        .run_if(|config: Res<PhysicsDebugConfig>| {
+                            config.mode.contains(DebugRenderMode::RIGID_BODY_AXES)
+                        })
+                        .after(PhysicsSet::Sync),
+                    debug_render_velocities
+                        .run_if(|config: Res<PhysicsDebugConfig>| {
+                            config.mode.contains(DebugRenderMode::VELOCITIES)
+                        })
                         .after(PhysicsSet::Sync),
                 ).chain()
             );
     }
 }
 
+/// Overrides the debug render color used for a specific collider.
+///
+/// Add this component to an entity to tint its collider shape, AABB and
+/// center-cross with a custom color, making it easy to pick out from the
+/// rest of the overlay. When absent the render systems fall back to their
+/// global default colors.
+#[derive(Reflect, Component)]
+#[reflect(Component)]
+pub struct ColliderDebugColor(pub Color);
+
+bitflags! {
+    /// Selects which physics debug overlays the [`PhysicsDebugPlugin`] draws.
+    ///
+    /// Toggle passes by ORing flags together, e.g.
+    /// `DebugRenderMode::COLLIDER_SHAPES | DebugRenderMode::CONTACT_POINTS`.
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub struct DebugRenderMode: u32 {
+        /// Renders collider shapes and their center crosses.
+        const COLLIDER_SHAPES = 1 << 0;
+        /// Renders the Axis-Aligned Bounding Boxes of [colliders](`Collider`).
+        const COLLIDER_AABBS = 1 << 1;
+        /// Renders contact points.
+        const CONTACT_POINTS = 1 << 2;
+        /// Renders contact normals.
+        const CONTACT_NORMALS = 1 << 3;
+        /// Renders the local axes of rigid bodies.
+        const RIGID_BODY_AXES = 1 << 4;
+        /// Renders linear and angular velocity vectors.
+        const VELOCITIES = 1 << 5;
+    }
+}
+
+impl Default for DebugRenderMode {
+    fn default() -> Self {
+        Self::COLLIDER_SHAPES | Self::COLLIDER_AABBS | Self::CONTACT_POINTS
+    }
+}
+
+// Reflect the bitflags as an opaque value (its `u32` bits) so [`PhysicsDebugConfig`]
+// stays editable through the reflection/inspector path.
+impl_reflect_value!(DebugRenderMode(Debug, PartialEq, Default));
+impl_from_reflect_value!(DebugRenderMode);
+
 /// Controls the [`PhysicsDebugPlugin`] configuration.
 #[derive(Reflect, Resource)]
 #[reflect(Resource)]
 pub struct PhysicsDebugConfig {
-    /// Renders the Axis-Aligned Bounding Boxes of [colliders](`Collider`).
-    pub render_aabbs: bool,
-    /// Renders contact points.
-    pub render_contacts: bool,
-    /// Renders collider shapes
-    pub render_colliders: bool,
+    /// Selects which overlays are drawn. See [`DebugRenderMode`].
+    pub mode: DebugRenderMode,
 }
 
 impl Default for PhysicsDebugConfig {
     fn default() -> Self {
         Self {
-            render_aabbs: true,
-            render_contacts: true,
-            render_colliders: true,
+            mode: DebugRenderMode::default(),
         }
     }
 }
 
-fn debug_render_colliders(cols: Query<(&Collider, &Transform)>, mut gizmos: Gizmos) {
-    for (col, transform) in cols.iter() {
+/// Controls the colors and sizes used by the [`PhysicsDebugPlugin`] overlays.
+///
+/// Every literal the render passes used to hardcode lives here, so the overlay
+/// can be fully restyled at runtime.
+#[derive(Reflect, Resource)]
+#[reflect(Resource)]
+pub struct DebugRenderStyle {
+    /// Color of collider shape outlines.
+    pub collider_color: Color,
+    /// Color of the cross drawn at each collider's center.
+    pub collider_cross_color: Color,
+    /// Color of collider AABBs.
+    pub aabb_color: Color,
+    /// Color of contact point crosses.
+    pub contact_point_color: Color,
+    /// Color of contact normal lines.
+    pub contact_normal_color: Color,
+    /// Multiplier applied to penetration depth to get the contact normal line length.
+    pub contact_normal_scale: f32,
+    /// Maximum length of a contact normal line, clamping the penetration-scaled length.
+    pub contact_normal_max_length: f32,
+    /// Color of rigid-body axes.
+    pub rigid_body_axes_color: Color,
+    /// Length of each rigid-body axis line.
+    pub rigid_body_axis_length: f32,
+    /// Color of linear velocity vectors.
+    pub velocity_color: Color,
+    /// Color of angular velocity indicators.
+    pub angular_velocity_color: Color,
+    /// Multiplier converting a body's speed into a velocity arrow length.
+    pub velocity_scale: f32,
+    /// Multiplier converting a body's angular speed into an angular-velocity indicator size.
+    pub angular_velocity_scale: f32,
+    /// Width of every gizmo line, mirrored into [`GizmoConfig`].
+    pub line_width: f32,
+    /// Arm length of the cross drawn at each contact point.
+    pub contact_cross_size: f32,
+    /// Arm length of the cross drawn at each collider's center.
+    pub collider_cross_size: f32,
+}
+
+impl Default for DebugRenderStyle {
+    fn default() -> Self {
+        Self {
+            collider_color: Color::WHITE,
+            collider_cross_color: Color::GRAY,
+            aabb_color: Color::GRAY,
+            contact_point_color: Color::CYAN,
+            contact_normal_color: Color::ORANGE,
+            contact_normal_scale: 50.0,
+            contact_normal_max_length: 1.0,
+            rigid_body_axes_color: Color::RED,
+            rigid_body_axis_length: 3.0,
+            velocity_color: Color::YELLOW,
+            angular_velocity_color: Color::SEA_GREEN,
+            velocity_scale: 1.0,
+            angular_velocity_scale: 1.0,
+            line_width: 1.0,
+            contact_cross_size: 0.3,
+            collider_cross_size: 3.0,
+        }
+    }
+}
+
+fn debug_render_colliders(
+    cols: Query<(&Collider, &Transform, Option<&ColliderDebugColor>)>,
+    style: Res<DebugRenderStyle>,
+    mut gizmos: Gizmos,
+) {
+    for (col, transform, custom_color) in cols.iter() {
         let shape = col.get_shape();
+        let color = custom_color.map_or(style.collider_color, |c| c.0);
+        let cross_color = custom_color.map_or(style.collider_cross_color, |c| c.0);
 
         // render a "+" at center of collider
         //    a
@@ -68,102 +201,517 @@ fn debug_render_colliders(cols: Query<(&Collider, &Transform)>, mut gizmos: Gizm
         //d -   - b
         //    |
         //    c
-        let x_sz = 3.0; // length of arm of cross at center
-        let a = transform.transform_point(Vec3::new(0.0, x_sz, 0.0)).truncate();
-        let b = transform.transform_point(Vec3::new(x_sz, 0.0, 0.0)).truncate();
-        let c = transform.transform_point(Vec3::new(0.0, -x_sz, 0.0)).truncate();
-        let d = transform.transform_point(Vec3::new(-x_sz, 0.0, 0.0)).truncate();
-        gizmos.line_2d(a, c, Color::GRAY);
-        gizmos.line_2d(b, d, Color::GRAY);
+        let x_sz = style.collider_cross_size; // length of arm of cross at center
+        let a = transform.transform_point(Vec3::new(0.0, x_sz, 0.0));
+        let b = transform.transform_point(Vec3::new(x_sz, 0.0, 0.0));
+        let c = transform.transform_point(Vec3::new(0.0, -x_sz, 0.0));
+        let d = transform.transform_point(Vec3::new(-x_sz, 0.0, 0.0));
+        #[cfg(feature = "2d")]
+        {
+            gizmos.line_2d(a.truncate(), c.truncate(), cross_color);
+            gizmos.line_2d(b.truncate(), d.truncate(), cross_color);
+        }
+        #[cfg(feature = "3d")]
+        {
+            let e = transform.transform_point(Vec3::new(0.0, 0.0, x_sz));
+            let f = transform.transform_point(Vec3::new(0.0, 0.0, -x_sz));
+            gizmos.line(a, c, cross_color);
+            gizmos.line(b, d, cross_color);
+            gizmos.line(e, f, cross_color);
+        }
 
         // render the collider shape
 
+        #[cfg(feature = "3d")]
+        {
+            if let Some(ball) = shape.as_ball() {
+                // three orthogonal great circles at the transformed center
+                let center = transform.transform_point(Vec3::ZERO);
+                gizmos.circle(center, Vec3::X, ball.radius, color);
+                gizmos.circle(center, Vec3::Y, ball.radius, color);
+                gizmos.circle(center, Vec3::Z, ball.radius, color);
+                continue;
+            }
+
+            if let Some(cuboid) = shape.as_cuboid() {
+                // 12 wireframe edges built from the 8 transformed corners
+                let h = cuboid.half_extents;
+                let corners: [Vec3; 8] = [
+                    transform.transform_point(Vec3::new(-h[0], -h[1], -h[2])),
+                    transform.transform_point(Vec3::new(h[0], -h[1], -h[2])),
+                    transform.transform_point(Vec3::new(h[0], h[1], -h[2])),
+                    transform.transform_point(Vec3::new(-h[0], h[1], -h[2])),
+                    transform.transform_point(Vec3::new(-h[0], -h[1], h[2])),
+                    transform.transform_point(Vec3::new(h[0], -h[1], h[2])),
+                    transform.transform_point(Vec3::new(h[0], h[1], h[2])),
+                    transform.transform_point(Vec3::new(-h[0], h[1], h[2])),
+                ];
+                const EDGES: [(usize, usize); 12] = [
+                    (0, 1), (1, 2), (2, 3), (3, 0), // -z face
+                    (4, 5), (5, 6), (6, 7), (7, 4), // +z face
+                    (0, 4), (1, 5), (2, 6), (3, 7), // edges along z
+                ];
+                for (i, j) in EDGES {
+                    gizmos.line(corners[i], corners[j], color);
+                }
+                continue;
+            }
+
+            if let Some(capsule) = shape.as_capsule() {
+                // two hemispheres (drawn as circles) at the segment ends, plus side lines
+                let top = transform.transform_point(Vec3::new(
+                    capsule.segment.a[0],
+                    capsule.segment.a[1],
+                    capsule.segment.a[2],
+                ));
+                let bottom = transform.transform_point(Vec3::new(
+                    capsule.segment.b[0],
+                    capsule.segment.b[1],
+                    capsule.segment.b[2],
+                ));
+                let axis = (top - bottom).normalize_or_zero();
+                gizmos.circle(top, axis, capsule.radius, color);
+                gizmos.circle(bottom, axis, capsule.radius, color);
+                // connect the two caps with side lines on both perpendicular axes
+                let perp1 = axis.any_orthonormal_vector() * capsule.radius;
+                let perp2 = axis.cross(perp1.normalize_or_zero()) * capsule.radius;
+                for offset in [perp1, -perp1, perp2, -perp2] {
+                    gizmos.line(top + offset, bottom + offset, color);
+                }
+                continue;
+            }
+
+            // General fallback: walk the shape's edge/triangulation list and draw each edge.
+            // Handles trimeshes and compound colliders built from Bevy meshes, plus
+            // heightfields, polylines and segments.
+            if debug_render_shape_edges(shape, transform, color, &mut gizmos) {
+                continue;
+            }
+
+            bevy::log::warn!("Can not render collider of this shape type at the mo.");
+            continue;
+        }
+
+        #[cfg(feature = "2d")]
         if let Some(ball) = shape.as_ball() {
-            gizmos.circle_2d(transform.translation.truncate(), ball.radius, Color::WHITE);
+            gizmos.circle_2d(transform.translation.truncate(), ball.radius, color);
             continue;
         }
 
+        #[cfg(feature = "2d")]
         if let Some(triangle) = shape.as_triangle() {
             let p1 = transform.transform_point(Vec3::new(triangle.a[0], triangle.a[1], 0.0)).truncate();
             let p2 = transform.transform_point(Vec3::new(triangle.b[0], triangle.b[1], 0.0)).truncate();
             let p3 = transform.transform_point(Vec3::new(triangle.c[0], triangle.c[1], 0.0)).truncate();
-            gizmos.line_2d(p1, p2, Color::WHITE);
-            gizmos.line_2d(p2, p3, Color::WHITE);
-            gizmos.line_2d(p3, p1, Color::WHITE);
+            gizmos.line_2d(p1, p2, color);
+            gizmos.line_2d(p2, p3, color);
+            gizmos.line_2d(p3, p1, color);
             continue;
         }
         
+        #[cfg(feature = "2d")]
         if let Some(poly) = shape.as_convex_polygon() {
             let last_p = poly.points().last().unwrap();
             let mut start_p =  transform.transform_point(Vec3::new(last_p.x, last_p.y, 0.0)).truncate();
             for i in 0..poly.points().len() {
                 let p = poly.points()[i];
                 let tmp = transform.transform_point(Vec3::new(p.x, p.y, 0.0)).truncate();
-                gizmos.line_2d(start_p, tmp, Color::WHITE);
+                gizmos.line_2d(start_p, tmp, color);
                 start_p = tmp;
             }
             continue;
         }
 
+        #[cfg(feature = "2d")]
         if let Some(cuboid) = shape.as_cuboid() {
             let points: Vec<Vec3> = cuboid.to_polyline().into_iter().map(|p| Vec3::new(p.x, p.y, 0.0)).collect();
             let mut start_p = transform.transform_point(*points.last().unwrap());
             for i in 0..points.len() {
                 let tmp = transform.transform_point(points[i]);
-                gizmos.line_2d(start_p.truncate(), tmp.truncate(), Color::WHITE);
+                gizmos.line_2d(start_p.truncate(), tmp.truncate(), color);
                 start_p = tmp;
             }
             continue;
         }
 
-        bevy::log::warn!("Can only render colliders for balls, cuboids, and polys at the mo.");
+        // General fallback: walk the shape's edge/triangulation list and draw each edge.
+        // Handles capsules, segments, polylines, rounded convex polygons, heightfields, triangle-meshes
+        // and compound colliders built from Bevy meshes.
+        #[cfg(feature = "2d")]
+        if debug_render_shape_edges(shape, transform, color, &mut gizmos) {
+            continue;
+        }
+
+        #[cfg(feature = "2d")]
+        bevy::log::warn!("Can not render collider of this shape type at the mo.");
+    }
+}
+
+/// Draws the edges of an arbitrary parry shape as gizmo lines, recursing into compound
+/// sub-shapes. Returns `true` if the shape was recognised and rendered.
+#[cfg(feature = "2d")]
+#[allow(clippy::unnecessary_cast)]
+fn debug_render_shape_edges(
+    shape: &dyn Shape,
+    transform: &Transform,
+    color: Color,
+    gizmos: &mut Gizmos,
+) -> bool {
+    // local-space point -> transformed 2D gizmo point
+    let tp = |x: Scalar, y: Scalar| {
+        transform
+            .transform_point(Vec3::new(x as f32, y as f32, 0.0))
+            .truncate()
+    };
+
+    if let Some(seg) = shape.as_segment() {
+        gizmos.line_2d(tp(seg.a.x, seg.a.y), tp(seg.b.x, seg.b.y), color);
+        return true;
+    }
+
+    if let Some(capsule) = shape.as_capsule() {
+        let a = capsule.segment.a;
+        let b = capsule.segment.b;
+        gizmos.circle_2d(tp(a.x, a.y), capsule.radius, color);
+        gizmos.circle_2d(tp(b.x, b.y), capsule.radius, color);
+        // the two straight sides, offset perpendicular to the capsule axis
+        let dir = (Vec2::new((b.x - a.x) as f32, (b.y - a.y) as f32)).normalize_or_zero();
+        let perp = Vec2::new(-dir.y, dir.x) * capsule.radius;
+        gizmos.line_2d(tp(a.x, a.y) + perp, tp(b.x, b.y) + perp, color);
+        gizmos.line_2d(tp(a.x, a.y) - perp, tp(b.x, b.y) - perp, color);
+        return true;
+    }
+
+    if let Some(polyline) = shape.as_polyline() {
+        for seg in polyline.segments() {
+            gizmos.line_2d(tp(seg.a.x, seg.a.y), tp(seg.b.x, seg.b.y), color);
+        }
+        return true;
+    }
+
+    if let Some(heightfield) = shape.as_heightfield() {
+        for seg in heightfield.segments() {
+            gizmos.line_2d(tp(seg.a.x, seg.a.y), tp(seg.b.x, seg.b.y), color);
+        }
+        return true;
+    }
+
+    if let Some(trimesh) = shape.as_trimesh() {
+        for tri in trimesh.triangles() {
+            gizmos.line_2d(tp(tri.a.x, tri.a.y), tp(tri.b.x, tri.b.y), color);
+            gizmos.line_2d(tp(tri.b.x, tri.b.y), tp(tri.c.x, tri.c.y), color);
+            gizmos.line_2d(tp(tri.c.x, tri.c.y), tp(tri.a.x, tri.a.y), color);
+        }
+        return true;
+    }
+
+    if let Some(poly) = shape.as_convex_polygon() {
+        let points = poly.points();
+        for (i, a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
+            gizmos.line_2d(tp(a.x, a.y), tp(b.x, b.y), color);
+        }
+        return true;
+    }
+
+    // rounded convex polygons just render their base polygon's edges
+    if let Some(round) = shape.as_round_convex_polygon() {
+        return debug_render_shape_edges(&round.inner_shape, transform, color, gizmos);
+    }
+
+    if let Some(compound) = shape.as_compound() {
+        let mut rendered = false;
+        for (iso, sub) in compound.shapes() {
+            let sub_transform = Transform {
+                translation: Vec3::new(
+                    iso.translation.x as f32,
+                    iso.translation.y as f32,
+                    0.0,
+                ),
+                rotation: Quat::from_rotation_z(iso.rotation.angle() as f32),
+                ..default()
+            };
+            let combined = transform.mul_transform(sub_transform);
+            rendered |= debug_render_shape_edges(&**sub, &combined, color, gizmos);
+        }
+        return rendered;
     }
+
+    false
 }
 
-fn debug_render_aabbs(aabbs: Query<&ColliderAabb>, mut gizmos: Gizmos) {
+/// Draws the edges of an arbitrary parry shape as gizmo lines, recursing into compound
+/// sub-shapes. Returns `true` if the shape was recognised and rendered.
+#[cfg(feature = "3d")]
+#[allow(clippy::unnecessary_cast)]
+fn debug_render_shape_edges(
+    shape: &dyn Shape,
+    transform: &Transform,
+    color: Color,
+    gizmos: &mut Gizmos,
+) -> bool {
+    // local-space point -> transformed 3D gizmo point
+    let tp = |x: Scalar, y: Scalar, z: Scalar| {
+        transform.transform_point(Vec3::new(x as f32, y as f32, z as f32))
+    };
+
+    if let Some(seg) = shape.as_segment() {
+        gizmos.line(tp(seg.a.x, seg.a.y, seg.a.z), tp(seg.b.x, seg.b.y, seg.b.z), color);
+        return true;
+    }
+
+    if let Some(polyline) = shape.as_polyline() {
+        for seg in polyline.segments() {
+            gizmos.line(tp(seg.a.x, seg.a.y, seg.a.z), tp(seg.b.x, seg.b.y, seg.b.z), color);
+        }
+        return true;
+    }
+
+    if let Some(heightfield) = shape.as_heightfield() {
+        for tri in heightfield.triangles() {
+            gizmos.line(tp(tri.a.x, tri.a.y, tri.a.z), tp(tri.b.x, tri.b.y, tri.b.z), color);
+            gizmos.line(tp(tri.b.x, tri.b.y, tri.b.z), tp(tri.c.x, tri.c.y, tri.c.z), color);
+            gizmos.line(tp(tri.c.x, tri.c.y, tri.c.z), tp(tri.a.x, tri.a.y, tri.a.z), color);
+        }
+        return true;
+    }
+
+    if let Some(trimesh) = shape.as_trimesh() {
+        for tri in trimesh.triangles() {
+            gizmos.line(tp(tri.a.x, tri.a.y, tri.a.z), tp(tri.b.x, tri.b.y, tri.b.z), color);
+            gizmos.line(tp(tri.b.x, tri.b.y, tri.b.z), tp(tri.c.x, tri.c.y, tri.c.z), color);
+            gizmos.line(tp(tri.c.x, tri.c.y, tri.c.z), tp(tri.a.x, tri.a.y, tri.a.z), color);
+        }
+        return true;
+    }
+
+    if let Some(poly) = shape.as_convex_polyhedron() {
+        let points = poly.points();
+        for edge in poly.edges() {
+            let a = points[edge.vertices[0] as usize];
+            let b = points[edge.vertices[1] as usize];
+            gizmos.line(tp(a.x, a.y, a.z), tp(b.x, b.y, b.z), color);
+        }
+        return true;
+    }
+
+    // rounded convex polyhedra just render their base polyhedron's edges
+    if let Some(round) = shape.as_round_convex_polyhedron() {
+        return debug_render_shape_edges(&round.inner_shape, transform, color, gizmos);
+    }
+
+    if let Some(compound) = shape.as_compound() {
+        let mut rendered = false;
+        for (iso, sub) in compound.shapes() {
+            let sub_transform = Transform {
+                translation: Vec3::new(
+                    iso.translation.x as f32,
+                    iso.translation.y as f32,
+                    iso.translation.z as f32,
+                ),
+                rotation: Quat::from_xyzw(
+                    iso.rotation.i as f32,
+                    iso.rotation.j as f32,
+                    iso.rotation.k as f32,
+                    iso.rotation.w as f32,
+                ),
+                ..default()
+            };
+            let combined = transform.mul_transform(sub_transform);
+            rendered |= debug_render_shape_edges(&**sub, &combined, color, gizmos);
+        }
+        return rendered;
+    }
+
+    false
+}
+
+fn debug_render_aabbs(
+    aabbs: Query<(&ColliderAabb, Option<&ColliderDebugColor>)>,
+    style: Res<DebugRenderStyle>,
+    mut gizmos: Gizmos,
+) {
     #[cfg(feature = "2d")]
-    for aabb in aabbs.iter() {
+    for (aabb, custom_color) in aabbs.iter() {
         gizmos.cuboid(
             Transform::from_scale(Vector::from(aabb.extents()).extend(0.0).as_f32())
                 .with_translation(Vector::from(aabb.center()).extend(0.0).as_f32()),
-            Color::GRAY,
+            custom_color.map_or(style.aabb_color, |c| c.0),
         );
     }
 
     #[cfg(feature = "3d")]
-    for aabb in aabbs.iter() {
+    for (aabb, custom_color) in aabbs.iter() {
         gizmos.cuboid(
             Transform::from_scale(Vector::from(aabb.extents()).as_f32())
                 .with_translation(Vector::from(aabb.center()).as_f32()),
-            Color::GRAY,
+            custom_color.map_or(style.aabb_color, |c| c.0),
         );
     }
 }
 
+/// Keeps [`GizmoConfig::line_width`] in sync with [`DebugRenderStyle::line_width`].
+fn sync_gizmo_config(style: Res<DebugRenderStyle>, mut config: ResMut<GizmoConfig>) {
+    if style.is_changed() {
+        config.line_width = style.line_width;
+    }
+}
+
 #[allow(clippy::unnecessary_cast)]
-fn debug_render_contacts(mut collisions: EventReader<Collision>, mut gizmos: Gizmos) {
+fn debug_render_contacts(
+    mut collisions: EventReader<Collision>,
+    config: Res<PhysicsDebugConfig>,
+    style: Res<DebugRenderStyle>,
+    mut gizmos: Gizmos,
+) {
+    let draw_points = config.mode.contains(DebugRenderMode::CONTACT_POINTS);
+    let draw_normals = config.mode.contains(DebugRenderMode::CONTACT_NORMALS);
+    let color = style.contact_point_color;
+    let sz = style.contact_cross_size;
+
+    let normal_color = style.contact_normal_color;
+    // Penetration-scaled, clamped length of the normal line.
+    let normal_length = |contact: &Contact| {
+        (contact.penetration as f32 * style.contact_normal_scale)
+            .max(0.0)
+            .min(style.contact_normal_max_length)
+    };
+
     #[cfg(feature = "2d")]
     for Collision(contact) in collisions.iter() {
         let p1 = contact.point1.as_f32();
         let p2 = contact.point2.as_f32();
 
-        gizmos.line_2d(p1 - Vec2::X * 0.3, p1 + Vec2::X * 0.3, Color::CYAN);
-        gizmos.line_2d(p1 - Vec2::Y * 0.3, p1 + Vec2::Y * 0.3, Color::CYAN);
+        if draw_points {
+            gizmos.line_2d(p1 - Vec2::X * sz, p1 + Vec2::X * sz, color);
+            gizmos.line_2d(p1 - Vec2::Y * sz, p1 + Vec2::Y * sz, color);
+
+            gizmos.line_2d(p2 - Vec2::X * sz, p2 + Vec2::X * sz, color);
+            gizmos.line_2d(p2 - Vec2::Y * sz, p2 + Vec2::Y * sz, color);
+        }
 
-        gizmos.line_2d(p2 - Vec2::X * 0.3, p2 + Vec2::X * 0.3, Color::CYAN);
-        gizmos.line_2d(p2 - Vec2::Y * 0.3, p2 + Vec2::Y * 0.3, Color::CYAN);
+        if draw_normals {
+            let normal = contact.normal.as_f32();
+            gizmos.line_2d(p1, p1 + normal * normal_length(contact), normal_color);
+        }
     }
     #[cfg(feature = "3d")]
     for Collision(contact) in collisions.iter() {
         let p1 = contact.point1.as_f32();
         let p2 = contact.point2.as_f32();
 
-        gizmos.line(p1 - Vec3::X * 0.3, p1 + Vec3::X * 0.3, Color::CYAN);
-        gizmos.line(p1 - Vec3::Y * 0.3, p1 + Vec3::Y * 0.3, Color::CYAN);
-        gizmos.line(p1 - Vec3::Z * 0.3, p1 + Vec3::Z * 0.3, Color::CYAN);
+        if draw_points {
+            gizmos.line(p1 - Vec3::X * sz, p1 + Vec3::X * sz, color);
+            gizmos.line(p1 - Vec3::Y * sz, p1 + Vec3::Y * sz, color);
+            gizmos.line(p1 - Vec3::Z * sz, p1 + Vec3::Z * sz, color);
 
-        gizmos.line(p2 - Vec3::X * 0.3, p2 + Vec3::X * 0.3, Color::CYAN);
-        gizmos.line(p2 - Vec3::Y * 0.3, p2 + Vec3::Y * 0.3, Color::CYAN);
-        gizmos.line(p2 - Vec3::Z * 0.3, p2 + Vec3::Z * 0.3, Color::CYAN);
+            gizmos.line(p2 - Vec3::X * sz, p2 + Vec3::X * sz, color);
+            gizmos.line(p2 - Vec3::Y * sz, p2 + Vec3::Y * sz, color);
+            gizmos.line(p2 - Vec3::Z * sz, p2 + Vec3::Z * sz, color);
+        }
+
+        if draw_normals {
+            let normal = contact.normal.as_f32();
+            gizmos.line(p1, p1 + normal * normal_length(contact), normal_color);
+        }
+    }
+}
+
+fn debug_render_axes(
+    bodies: Query<&Transform, With<RigidBody>>,
+    style: Res<DebugRenderStyle>,
+    mut gizmos: Gizmos,
+) {
+    let len = style.rigid_body_axis_length;
+    let color = style.rigid_body_axes_color;
+
+    #[cfg(feature = "2d")]
+    for transform in bodies.iter() {
+        let center = transform.translation.truncate();
+        gizmos.line_2d(center, center + transform.local_x().truncate() * len, color);
+        gizmos.line_2d(center, center + transform.local_y().truncate() * len, color);
+    }
+
+    #[cfg(feature = "3d")]
+    for transform in bodies.iter() {
+        let center = transform.translation;
+        gizmos.line(center, center + transform.local_x() * len, color);
+        gizmos.line(center, center + transform.local_y() * len, color);
+        gizmos.line(center, center + transform.local_z() * len, color);
+    }
+}
+
+#[allow(clippy::unnecessary_cast)]
+fn debug_render_velocities(
+    bodies: Query<
+        (
+            &Transform,
+            Option<&LinearVelocity>,
+            Option<&AngularVelocity>,
+        ),
+        With<RigidBody>,
+    >,
+    style: Res<DebugRenderStyle>,
+    mut gizmos: Gizmos,
+) {
+    #[cfg(feature = "2d")]
+    for (transform, lin_vel, ang_vel) in bodies.iter() {
+        let center = transform.translation.truncate();
+
+        if let Some(lin_vel) = lin_vel {
+            let vel = lin_vel.0.as_f32();
+            let tip = center + vel * style.velocity_scale;
+            gizmos.line_2d(center, tip, style.velocity_color);
+
+            // arrowhead: two short back-angled segments from the tip
+            let dir = vel.normalize_or_zero();
+            if dir != Vec2::ZERO {
+                let head = (vel.length() * style.velocity_scale * 0.2).min(style.collider_cross_size);
+                let left = Vec2::new(-dir.y, dir.x);
+                gizmos.line_2d(tip, tip - dir * head + left * head * 0.5, style.velocity_color);
+                gizmos.line_2d(tip, tip - dir * head - left * head * 0.5, style.velocity_color);
+            }
+        }
+
+        if let Some(ang_vel) = ang_vel {
+            // short arc whose radius scales with the angular speed
+            let omega = ang_vel.0 as f32;
+            let radius = omega.abs() * style.angular_velocity_scale;
+            let segments = 16;
+            let sweep = omega.clamp(-std::f32::consts::TAU, std::f32::consts::TAU);
+            let mut prev = center + Vec2::X * radius;
+            for i in 1..=segments {
+                let angle = sweep * i as f32 / segments as f32;
+                let next = center + Vec2::new(angle.cos(), angle.sin()) * radius;
+                gizmos.line_2d(prev, next, style.angular_velocity_color);
+                prev = next;
+            }
+        }
+    }
+
+    #[cfg(feature = "3d")]
+    for (transform, lin_vel, ang_vel) in bodies.iter() {
+        let center = transform.translation;
+
+        if let Some(lin_vel) = lin_vel {
+            let vel = lin_vel.0.as_f32();
+            let tip = center + vel * style.velocity_scale;
+            gizmos.line(center, tip, style.velocity_color);
+
+            // arrowhead: two short back-angled segments from the tip
+            let dir = vel.normalize_or_zero();
+            if dir != Vec3::ZERO {
+                let head = (vel.length() * style.velocity_scale * 0.2).min(style.collider_cross_size);
+                let side = dir.any_orthonormal_vector();
+                gizmos.line(tip, tip - dir * head + side * head * 0.5, style.velocity_color);
+                gizmos.line(tip, tip - dir * head - side * head * 0.5, style.velocity_color);
+            }
+        }
+
+        if let Some(ang_vel) = ang_vel {
+            // a line along the angular-velocity axis, scaled by its magnitude
+            let omega = ang_vel.0.as_f32();
+            gizmos.line(center, center + omega * style.angular_velocity_scale, style.angular_velocity_color);
+        }
     }
 }